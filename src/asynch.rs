@@ -0,0 +1,32 @@
+// No executor-agnostic way to add a `Send` bound to the returned futures in
+// a no_std, single-executor embedded context, same tradeoff embedded-hal-async
+// makes.
+#![allow(async_fn_in_trait)]
+
+use crate::{GenericArray, ParallelBus, PinState, Same};
+
+pub trait AsyncInputBus: ParallelBus {
+    type Error: core::fmt::Debug;
+    async fn read_bus(&self) -> Result<GenericArray<PinState, Self::BusWidth>, Self::Error>;
+}
+
+pub trait AsyncOutputBus: ParallelBus {
+    type Error: core::fmt::Debug;
+    async fn write_bus(
+        &mut self,
+        states: GenericArray<PinState, Self::BusWidth>,
+    ) -> Result<(), Self::Error>;
+}
+
+pub trait AsyncIoBus<TInput, TOutput>: ParallelBus
+where
+    TInput: AsyncInputBus + AsyncIoBus<TInput, TOutput>,
+    TOutput: AsyncOutputBus + AsyncIoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    type IntoInputError: core::fmt::Debug;
+    type IntoOutputError: core::fmt::Debug;
+    async fn into_input_bus(self) -> Result<TInput, Self::IntoInputError>;
+    async fn into_output_bus(self) -> Result<TOutput, Self::IntoOutputError>;
+}
@@ -1,4 +1,8 @@
 #![no_std]
+// generic-array 1.x dropped `ArrayLength`/`GenericArray` in favor of const
+// generics; staying on 0.14 keeps `BusWidth` expressed in terms of typenum,
+// which the rest of the crate's generics (IoBus, SwitchableBus, ...) rely on.
+#![allow(deprecated)]
 
 use core::fmt;
 
@@ -8,6 +12,15 @@ pub use hal::digital::v2::PinState;
 pub use generic_array;
 use generic_array::{ArrayLength, GenericArray};
 
+#[cfg(feature = "async")]
+pub mod asynch;
+#[cfg(feature = "shared")]
+pub mod shared;
+#[cfg(feature = "alloc")]
+pub mod split;
+pub mod strobed;
+pub mod word;
+
 pub trait Same<T> {}
 
 impl<T> Same<T> for T {}
@@ -19,6 +32,16 @@ pub trait ParallelBus {
 pub trait InputBus: ParallelBus {
     type Error: fmt::Debug;
     fn read_bus(&self) -> Result<GenericArray<PinState, Self::BusWidth>, Self::Error>;
+
+    fn read_bus_many(
+        &self,
+        out: &mut [GenericArray<PinState, Self::BusWidth>],
+    ) -> Result<(), Self::Error> {
+        for slot in out.iter_mut() {
+            *slot = self.read_bus()?;
+        }
+        Ok(())
+    }
 }
 
 pub trait OutputBus: ParallelBus {
@@ -27,6 +50,43 @@ pub trait OutputBus: ParallelBus {
         &mut self,
         states: GenericArray<PinState, Self::BusWidth>,
     ) -> Result<(), Self::Error>;
+
+    fn write_bus_many(
+        &mut self,
+        words: impl Iterator<Item = GenericArray<PinState, Self::BusWidth>>,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        for word in words {
+            self.write_bus(word)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: ParallelBus + ?Sized> ParallelBus for &T {
+    type BusWidth = T::BusWidth;
+}
+
+impl<T: ParallelBus + ?Sized> ParallelBus for &mut T {
+    type BusWidth = T::BusWidth;
+}
+
+impl<T: InputBus + ?Sized> InputBus for &T {
+    type Error = T::Error;
+
+    fn read_bus(&self) -> Result<GenericArray<PinState, Self::BusWidth>, Self::Error> {
+        (**self).read_bus()
+    }
+}
+
+impl<T: OutputBus + ?Sized> OutputBus for &mut T {
+    type Error = T::Error;
+
+    fn write_bus(&mut self, states: GenericArray<PinState, Self::BusWidth>) -> Result<(), Self::Error> {
+        (**self).write_bus(states)
+    }
 }
 
 pub trait IoBus<TInput, TOutput>: ParallelBus
@@ -0,0 +1,135 @@
+use core::cell::RefCell;
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+
+use crate::{GenericArray, InputBus, IoBus, OutputBus, ParallelBus, PinState, Same, SwitchableBus};
+
+#[derive(Debug)]
+pub enum BusError<E> {
+    Bus(E),
+    Contended,
+}
+
+pub struct SharedBus<M, B>
+where
+    M: RawMutex,
+{
+    bus: Mutex<M, RefCell<B>>,
+}
+
+impl<M, B> SharedBus<M, B>
+where
+    M: RawMutex,
+{
+    pub const fn new(bus: B) -> Self {
+        Self {
+            bus: Mutex::new(RefCell::new(bus)),
+        }
+    }
+
+    pub fn proxy(&self) -> BusProxy<'_, M, B> {
+        BusProxy { bus: &self.bus }
+    }
+}
+
+pub struct BusProxy<'a, M, B>
+where
+    M: RawMutex,
+{
+    bus: &'a Mutex<M, RefCell<B>>,
+}
+
+impl<M, B> Clone for BusProxy<'_, M, B>
+where
+    M: RawMutex,
+{
+    fn clone(&self) -> Self {
+        Self { bus: self.bus }
+    }
+}
+
+impl<M, B> ParallelBus for BusProxy<'_, M, B>
+where
+    M: RawMutex,
+    B: ParallelBus,
+{
+    type BusWidth = B::BusWidth;
+}
+
+impl<M, B> InputBus for BusProxy<'_, M, B>
+where
+    M: RawMutex,
+    B: InputBus,
+{
+    type Error = BusError<B::Error>;
+
+    fn read_bus(&self) -> Result<GenericArray<PinState, Self::BusWidth>, Self::Error> {
+        self.bus.lock(|cell| {
+            cell.try_borrow()
+                .map_err(|_| BusError::Contended)?
+                .read_bus()
+                .map_err(BusError::Bus)
+        })
+    }
+}
+
+impl<M, B> OutputBus for BusProxy<'_, M, B>
+where
+    M: RawMutex,
+    B: OutputBus,
+{
+    type Error = BusError<B::Error>;
+
+    fn write_bus(&mut self, states: GenericArray<PinState, Self::BusWidth>) -> Result<(), Self::Error> {
+        self.bus.lock(|cell| {
+            cell.try_borrow_mut()
+                .map_err(|_| BusError::Contended)?
+                .write_bus(states)
+                .map_err(BusError::Bus)
+        })
+    }
+}
+
+impl<'a, M, B> BusProxy<'a, M, B>
+where
+    M: RawMutex,
+{
+    pub fn switch_to_input_bus<TInput, TOutput>(
+        &mut self,
+    ) -> Result<(), BusError<<B as IoBus<TInput, TOutput>>::IntoInputError>>
+    where
+        B: SwitchableBus<TInput, TOutput>,
+        TInput: InputBus + IoBus<TInput, TOutput>,
+        TOutput: OutputBus + IoBus<TInput, TOutput>,
+        <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+        <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    {
+        self.bus.lock(|cell| {
+            cell.try_borrow_mut()
+                .map_err(|_| BusError::Contended)?
+                .switch_to_input_bus()
+                .map(|_| ())
+                .map_err(BusError::Bus)
+        })
+    }
+
+    pub fn switch_to_output_bus<TInput, TOutput>(
+        &mut self,
+    ) -> Result<(), BusError<<B as IoBus<TInput, TOutput>>::IntoOutputError>>
+    where
+        B: SwitchableBus<TInput, TOutput>,
+        TInput: InputBus + IoBus<TInput, TOutput>,
+        TOutput: OutputBus + IoBus<TInput, TOutput>,
+        <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+        <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    {
+        self.bus.lock(|cell| {
+            cell.try_borrow_mut()
+                .map_err(|_| BusError::Contended)?
+                .switch_to_output_bus()
+                .map(|_| ())
+                .map_err(BusError::Bus)
+        })
+    }
+}
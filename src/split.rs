@@ -0,0 +1,291 @@
+extern crate alloc;
+
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::{
+    BidirectionBus, DirectionErasedBus, GenericArray, InputBus, IoBus, OutputBus, ParallelBus,
+    PinState, Same,
+};
+
+#[derive(Debug)]
+pub enum SplitError<SwitchErr, BusErr> {
+    WrongDirection,
+    Contended,
+    Poisoned,
+    Switch(SwitchErr),
+    Bus(BusErr),
+}
+
+impl<SwitchErr, BusErr> From<SwitchErr> for SplitError<SwitchErr, BusErr> {
+    fn from(err: SwitchErr) -> Self {
+        SplitError::Switch(err)
+    }
+}
+
+#[derive(Debug)]
+pub enum JoinError {
+    StillSplit,
+    Poisoned,
+}
+
+type InputError<TInput, TOutput> =
+    SplitError<<TOutput as IoBus<TInput, TOutput>>::IntoInputError, <TInput as InputBus>::Error>;
+
+type OutputError<TInput, TOutput> =
+    SplitError<<TInput as IoBus<TInput, TOutput>>::IntoOutputError, <TOutput as OutputBus>::Error>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Input,
+    Output,
+}
+
+struct SplitState<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    // `None` means a previous switch left no bus behind (the underlying
+    // `IoBus::into_*_bus` conversion consumes `self`, so a failed conversion
+    // has nothing to hand back) rather than a transient mid-swap state;
+    // every accessor below treats it as `SplitError::Poisoned` rather than
+    // panicking.
+    bus: Option<DirectionErasedBus<TInput, TOutput>>,
+    active: Direction,
+}
+
+pub struct InputHalf<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    state: Rc<RefCell<SplitState<TInput, TOutput>>>,
+}
+
+pub struct OutputHalf<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    state: Rc<RefCell<SplitState<TInput, TOutput>>>,
+}
+
+impl<TInput, TOutput> BidirectionBus<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    pub fn split(self) -> (InputHalf<TInput, TOutput>, OutputHalf<TInput, TOutput>) {
+        let bus: DirectionErasedBus<TInput, TOutput> = self.into();
+        let active = match bus {
+            DirectionErasedBus::InputBus(_) => Direction::Input,
+            DirectionErasedBus::OutputBus(_) => Direction::Output,
+        };
+        let state = Rc::new(RefCell::new(SplitState {
+            bus: Some(bus),
+            active,
+        }));
+        (
+            InputHalf {
+                state: state.clone(),
+            },
+            OutputHalf { state },
+        )
+    }
+}
+
+pub fn join<TInput, TOutput>(
+    input: InputHalf<TInput, TOutput>,
+    output: OutputHalf<TInput, TOutput>,
+) -> Result<BidirectionBus<TInput, TOutput>, JoinError>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    drop(output.state);
+    let state = Rc::try_unwrap(input.state).map_err(|_| JoinError::StillSplit)?;
+    let bus = state.into_inner().bus.ok_or(JoinError::Poisoned)?;
+    Ok(bus.into())
+}
+
+impl<TInput, TOutput> InputHalf<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    pub fn switch_to_input(&self) -> Result<(), InputError<TInput, TOutput>> {
+        let mut state = self.state.try_borrow_mut().map_err(|_| SplitError::Contended)?;
+        if state.active != Direction::Input {
+            let bus = state.bus.take().ok_or(SplitError::Poisoned)?;
+            match bus.into_input_bus() {
+                Ok(bus) => {
+                    state.bus = Some(DirectionErasedBus::InputBus(bus));
+                    state.active = Direction::Input;
+                }
+                Err(err) => return Err(SplitError::Switch(err)),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn read_bus(
+        &self,
+    ) -> Result<GenericArray<PinState, TInput::BusWidth>, InputError<TInput, TOutput>> {
+        let state = self.state.try_borrow().map_err(|_| SplitError::Contended)?;
+        if state.active != Direction::Input {
+            return Err(SplitError::WrongDirection);
+        }
+        match state.bus.as_ref().ok_or(SplitError::Poisoned)? {
+            DirectionErasedBus::InputBus(bus) => bus.read_bus().map_err(SplitError::Bus),
+            DirectionErasedBus::OutputBus(_) => Err(SplitError::WrongDirection),
+        }
+    }
+}
+
+impl<TInput, TOutput> OutputHalf<TInput, TOutput>
+where
+    TInput: InputBus + IoBus<TInput, TOutput>,
+    TOutput: OutputBus + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+{
+    pub fn switch_to_output(&self) -> Result<(), OutputError<TInput, TOutput>> {
+        let mut state = self.state.try_borrow_mut().map_err(|_| SplitError::Contended)?;
+        if state.active != Direction::Output {
+            let bus = state.bus.take().ok_or(SplitError::Poisoned)?;
+            match bus.into_output_bus() {
+                Ok(bus) => {
+                    state.bus = Some(DirectionErasedBus::OutputBus(bus));
+                    state.active = Direction::Output;
+                }
+                Err(err) => return Err(SplitError::Switch(err)),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_bus(
+        &mut self,
+        states: GenericArray<PinState, TOutput::BusWidth>,
+    ) -> Result<(), OutputError<TInput, TOutput>> {
+        let mut state = self.state.try_borrow_mut().map_err(|_| SplitError::Contended)?;
+        if state.active != Direction::Output {
+            return Err(SplitError::WrongDirection);
+        }
+        match state.bus.as_mut().ok_or(SplitError::Poisoned)? {
+            DirectionErasedBus::OutputBus(bus) => bus.write_bus(states).map_err(SplitError::Bus),
+            DirectionErasedBus::InputBus(_) => Err(SplitError::WrongDirection),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use generic_array::typenum::U1;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct AlwaysFails;
+
+    struct MockInput;
+    struct MockOutput;
+
+    impl ParallelBus for MockInput {
+        type BusWidth = U1;
+    }
+
+    impl ParallelBus for MockOutput {
+        type BusWidth = U1;
+    }
+
+    impl InputBus for MockInput {
+        type Error = Infallible;
+
+        fn read_bus(&self) -> Result<GenericArray<PinState, U1>, Self::Error> {
+            Ok(GenericArray::from([PinState::Low]))
+        }
+    }
+
+    impl OutputBus for MockOutput {
+        type Error = Infallible;
+
+        fn write_bus(&mut self, _states: GenericArray<PinState, U1>) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    // `MockOutput` can never be switched back into a `MockInput`, so every
+    // `switch_to_input` on a bus currently held as `MockOutput` fails and
+    // exercises the poisoning path below.
+    impl IoBus<MockInput, MockOutput> for MockInput {
+        type IntoInputError = AlwaysFails;
+        type IntoOutputError = AlwaysFails;
+
+        fn into_input_bus(self) -> Result<MockInput, Self::IntoInputError> {
+            Ok(self)
+        }
+
+        fn into_output_bus(self) -> Result<MockOutput, Self::IntoOutputError> {
+            Err(AlwaysFails)
+        }
+    }
+
+    impl IoBus<MockInput, MockOutput> for MockOutput {
+        type IntoInputError = AlwaysFails;
+        type IntoOutputError = AlwaysFails;
+
+        fn into_input_bus(self) -> Result<MockInput, Self::IntoInputError> {
+            Err(AlwaysFails)
+        }
+
+        fn into_output_bus(self) -> Result<MockOutput, Self::IntoOutputError> {
+            Ok(self)
+        }
+    }
+
+    fn split_as_output() -> (InputHalf<MockInput, MockOutput>, OutputHalf<MockInput, MockOutput>) {
+        let bus: BidirectionBus<MockInput, MockOutput> =
+            DirectionErasedBus::OutputBus(MockOutput).into();
+        bus.split()
+    }
+
+    #[test]
+    fn failed_switch_returns_switch_error() {
+        let (input, _output) = split_as_output();
+        assert!(matches!(input.switch_to_input(), Err(SplitError::Switch(AlwaysFails))));
+    }
+
+    #[test]
+    fn failed_switch_poisons_the_other_half_instead_of_panicking() {
+        let (input, mut output) = split_as_output();
+        assert!(input.switch_to_input().is_err());
+
+        // The conversion consumed the only bus instance, so the half that
+        // still believes it's active can no longer reach it.
+        let states = GenericArray::from([PinState::Low]);
+        assert!(matches!(output.write_bus(states), Err(SplitError::Poisoned)));
+    }
+
+    #[test]
+    fn failed_switch_poisons_join() {
+        let (input, output) = split_as_output();
+        assert!(input.switch_to_input().is_err());
+        assert!(matches!(join(input, output), Err(JoinError::Poisoned)));
+    }
+}
@@ -0,0 +1,285 @@
+use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::digital::v2::OutputPin;
+use generic_array::typenum::{IsLessOrEqual, LeEq, B1, U8};
+
+use crate::word::{WordBus, WordConfig};
+use crate::{InputBus, IoBus, OutputBus, ParallelBus, Same, SwitchableBus};
+
+#[derive(Debug)]
+pub enum StrobedBusError<BusErr, PinErr, IntoInErr, IntoOutErr> {
+    Bus(BusErr),
+    Pin(PinErr),
+    IntoInputBus(IntoInErr),
+    IntoOutputBus(IntoOutErr),
+}
+
+type Error<BUS, TInput, TOutput, BusErr, PinErr> = StrobedBusError<
+    BusErr,
+    PinErr,
+    <BUS as IoBus<TInput, TOutput>>::IntoInputError,
+    <BUS as IoBus<TInput, TOutput>>::IntoOutputError,
+>;
+
+pub trait ParallelInterface {
+    type Error;
+
+    fn write_command(&mut self, byte: u8) -> Result<(), Self::Error>;
+    fn write_data(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+    fn read_data(&mut self, data: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+macro_rules! impl_write_data_read_data {
+    () => {
+        fn write_data(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+            for &byte in data {
+                self.write_byte(byte, false)?;
+            }
+            Ok(())
+        }
+
+        fn read_data(&mut self, data: &mut [u8]) -> Result<(), Self::Error> {
+            for slot in data {
+                *slot = self.read_byte()?;
+            }
+            Ok(())
+        }
+    };
+}
+
+pub struct Intel8080Bus<BUS, TInput, TOutput, CS, WR, RD, DC, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    WR: OutputPin<Error = PinErr>,
+    RD: OutputPin<Error = PinErr>,
+    DC: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+{
+    bus: BUS,
+    cs: CS,
+    wr: WR,
+    rd: RD,
+    dc: DC,
+    delay: DELAY,
+    setup_delay_us: u32,
+    _marker: core::marker::PhantomData<(TInput, TOutput)>,
+}
+
+impl<BUS, TInput, TOutput, CS, WR, RD, DC, BusErr, PinErr, DELAY>
+    Intel8080Bus<BUS, TInput, TOutput, CS, WR, RD, DC, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    WR: OutputPin<Error = PinErr>,
+    RD: OutputPin<Error = PinErr>,
+    DC: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+    U8: IsLessOrEqual<<TOutput as ParallelBus>::BusWidth>,
+    LeEq<U8, <TOutput as ParallelBus>::BusWidth>: Same<B1>,
+{
+    pub fn new(bus: BUS, cs: CS, wr: WR, rd: RD, dc: DC, delay: DELAY, setup_delay_us: u32) -> Self {
+        Self {
+            bus,
+            cs,
+            wr,
+            rd,
+            dc,
+            delay,
+            setup_delay_us,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn write_byte(
+        &mut self,
+        byte: u8,
+        command: bool,
+    ) -> Result<(), Error<BUS, TInput, TOutput, BusErr, PinErr>> {
+        let out = self
+            .bus
+            .switch_to_output_bus()
+            .map_err(StrobedBusError::IntoOutputBus)?;
+        self.dc.set_state((!command).into()).map_err(StrobedBusError::Pin)?;
+        WordBus::new(out, WordConfig::default())
+            .write_word(byte)
+            .map_err(StrobedBusError::Bus)?;
+        self.cs.set_low().map_err(StrobedBusError::Pin)?;
+        self.wr.set_low().map_err(StrobedBusError::Pin)?;
+        self.delay.delay_us(self.setup_delay_us);
+        self.wr.set_high().map_err(StrobedBusError::Pin)?;
+        self.cs.set_high().map_err(StrobedBusError::Pin)?;
+        Ok(())
+    }
+
+    fn read_byte(
+        &mut self,
+    ) -> Result<u8, Error<BUS, TInput, TOutput, BusErr, PinErr>> {
+        self.dc.set_high().map_err(StrobedBusError::Pin)?;
+        let inp = self
+            .bus
+            .switch_to_input_bus()
+            .map_err(StrobedBusError::IntoInputBus)?;
+        self.cs.set_low().map_err(StrobedBusError::Pin)?;
+        self.rd.set_low().map_err(StrobedBusError::Pin)?;
+        self.delay.delay_us(self.setup_delay_us);
+        let word = WordBus::new(inp, WordConfig::default())
+            .read_word()
+            .map_err(StrobedBusError::Bus)?;
+        self.rd.set_high().map_err(StrobedBusError::Pin)?;
+        self.cs.set_high().map_err(StrobedBusError::Pin)?;
+        Ok(word as u8)
+    }
+}
+
+impl<BUS, TInput, TOutput, CS, WR, RD, DC, BusErr, PinErr, DELAY> ParallelInterface
+    for Intel8080Bus<BUS, TInput, TOutput, CS, WR, RD, DC, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    WR: OutputPin<Error = PinErr>,
+    RD: OutputPin<Error = PinErr>,
+    DC: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+    U8: IsLessOrEqual<<TOutput as ParallelBus>::BusWidth>,
+    LeEq<U8, <TOutput as ParallelBus>::BusWidth>: Same<B1>,
+{
+    type Error = Error<BUS, TInput, TOutput, BusErr, PinErr>;
+
+    fn write_command(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.write_byte(byte, true)
+    }
+
+    impl_write_data_read_data!();
+}
+
+pub struct Motorola6800Bus<BUS, TInput, TOutput, CS, RW, E, RS, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    RW: OutputPin<Error = PinErr>,
+    E: OutputPin<Error = PinErr>,
+    RS: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+{
+    bus: BUS,
+    cs: CS,
+    rw: RW,
+    e: E,
+    rs: RS,
+    delay: DELAY,
+    setup_delay_us: u32,
+    _marker: core::marker::PhantomData<(TInput, TOutput)>,
+}
+
+impl<BUS, TInput, TOutput, CS, RW, E, RS, BusErr, PinErr, DELAY>
+    Motorola6800Bus<BUS, TInput, TOutput, CS, RW, E, RS, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    RW: OutputPin<Error = PinErr>,
+    E: OutputPin<Error = PinErr>,
+    RS: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+    U8: IsLessOrEqual<<TOutput as ParallelBus>::BusWidth>,
+    LeEq<U8, <TOutput as ParallelBus>::BusWidth>: Same<B1>,
+{
+    pub fn new(bus: BUS, cs: CS, rw: RW, e: E, rs: RS, delay: DELAY, setup_delay_us: u32) -> Self {
+        Self {
+            bus,
+            cs,
+            rw,
+            e,
+            rs,
+            delay,
+            setup_delay_us,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn write_byte(
+        &mut self,
+        byte: u8,
+        command: bool,
+    ) -> Result<(), Error<BUS, TInput, TOutput, BusErr, PinErr>> {
+        let out = self
+            .bus
+            .switch_to_output_bus()
+            .map_err(StrobedBusError::IntoOutputBus)?;
+        self.rs.set_state((!command).into()).map_err(StrobedBusError::Pin)?;
+        self.rw.set_low().map_err(StrobedBusError::Pin)?;
+        WordBus::new(out, WordConfig::default())
+            .write_word(byte)
+            .map_err(StrobedBusError::Bus)?;
+        self.cs.set_low().map_err(StrobedBusError::Pin)?;
+        self.e.set_high().map_err(StrobedBusError::Pin)?;
+        self.delay.delay_us(self.setup_delay_us);
+        self.e.set_low().map_err(StrobedBusError::Pin)?;
+        self.cs.set_high().map_err(StrobedBusError::Pin)?;
+        Ok(())
+    }
+
+    fn read_byte(
+        &mut self,
+    ) -> Result<u8, Error<BUS, TInput, TOutput, BusErr, PinErr>> {
+        self.rs.set_high().map_err(StrobedBusError::Pin)?;
+        self.rw.set_high().map_err(StrobedBusError::Pin)?;
+        let inp = self
+            .bus
+            .switch_to_input_bus()
+            .map_err(StrobedBusError::IntoInputBus)?;
+        self.cs.set_low().map_err(StrobedBusError::Pin)?;
+        self.e.set_high().map_err(StrobedBusError::Pin)?;
+        self.delay.delay_us(self.setup_delay_us);
+        let word = WordBus::new(inp, WordConfig::default())
+            .read_word()
+            .map_err(StrobedBusError::Bus)?;
+        self.e.set_low().map_err(StrobedBusError::Pin)?;
+        self.cs.set_high().map_err(StrobedBusError::Pin)?;
+        Ok(word as u8)
+    }
+}
+
+impl<BUS, TInput, TOutput, CS, RW, E, RS, BusErr, PinErr, DELAY> ParallelInterface
+    for Motorola6800Bus<BUS, TInput, TOutput, CS, RW, E, RS, BusErr, PinErr, DELAY>
+where
+    BUS: SwitchableBus<TInput, TOutput>,
+    TInput: InputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    TOutput: OutputBus<Error = BusErr> + IoBus<TInput, TOutput>,
+    <TInput as ParallelBus>::BusWidth: Same<<TOutput as ParallelBus>::BusWidth>,
+    <TOutput as ParallelBus>::BusWidth: Same<<TInput as ParallelBus>::BusWidth>,
+    CS: OutputPin<Error = PinErr>,
+    RW: OutputPin<Error = PinErr>,
+    E: OutputPin<Error = PinErr>,
+    RS: OutputPin<Error = PinErr>,
+    DELAY: DelayUs<u32>,
+    U8: IsLessOrEqual<<TOutput as ParallelBus>::BusWidth>,
+    LeEq<U8, <TOutput as ParallelBus>::BusWidth>: Same<B1>,
+{
+    type Error = Error<BUS, TInput, TOutput, BusErr, PinErr>;
+
+    fn write_command(&mut self, byte: u8) -> Result<(), Self::Error> {
+        self.write_byte(byte, true)
+    }
+
+    impl_write_data_read_data!();
+}
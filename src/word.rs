@@ -0,0 +1,185 @@
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::{IsLessOrEqual, LeEq, Unsigned, B1, U16, U32, U64, U8};
+use generic_array::GenericArray;
+
+use crate::{InputBus, OutputBus, ParallelBus, PinState, Same};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    LsbFirst,
+    MsbFirst,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    ActiveHigh,
+    ActiveLow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordConfig {
+    pub bit_order: BitOrder,
+    pub polarity: Polarity,
+}
+
+impl Default for WordConfig {
+    fn default() -> Self {
+        Self {
+            bit_order: BitOrder::LsbFirst,
+            polarity: Polarity::ActiveHigh,
+        }
+    }
+}
+
+fn bit_to_state(bit: bool, polarity: Polarity) -> PinState {
+    match polarity {
+        Polarity::ActiveHigh => PinState::from(bit),
+        Polarity::ActiveLow => PinState::from(!bit),
+    }
+}
+
+fn state_to_bit(state: PinState, polarity: Polarity) -> bool {
+    let high = state == PinState::High;
+    match polarity {
+        Polarity::ActiveHigh => high,
+        Polarity::ActiveLow => !high,
+    }
+}
+
+fn bus_width<B: ParallelBus>() -> usize {
+    <B::BusWidth as Unsigned>::to_usize()
+}
+
+fn bit_index(i: usize, width: usize, bit_order: BitOrder) -> usize {
+    match bit_order {
+        BitOrder::LsbFirst => i,
+        BitOrder::MsbFirst => width - 1 - i,
+    }
+}
+
+fn states_for_word<B: ParallelBus>(value: u64, config: WordConfig) -> GenericArray<PinState, B::BusWidth> {
+    let width = bus_width::<B>();
+    GenericArray::generate(|i| {
+        let bit = bit_index(i, width, config.bit_order);
+        bit_to_state((value >> bit) & 1 == 1, config.polarity)
+    })
+}
+
+fn word_from_states<B: ParallelBus>(states: &GenericArray<PinState, B::BusWidth>, config: WordConfig) -> u64 {
+    let width = bus_width::<B>();
+    let mut value = 0u64;
+    for (i, state) in states.iter().enumerate() {
+        if state_to_bit(*state, config.polarity) {
+            value |= 1 << bit_index(i, width, config.bit_order);
+        }
+    }
+    value
+}
+
+pub trait WordWidth: Into<u64> {
+    type Bits: Unsigned;
+}
+
+impl WordWidth for u8 {
+    type Bits = U8;
+}
+
+impl WordWidth for u16 {
+    type Bits = U16;
+}
+
+impl WordWidth for u32 {
+    type Bits = U32;
+}
+
+impl WordWidth for u64 {
+    type Bits = U64;
+}
+
+pub struct WordBus<B> {
+    bus: B,
+    config: WordConfig,
+}
+
+impl<B> WordBus<B> {
+    pub const fn new(bus: B, config: WordConfig) -> Self {
+        Self { bus, config }
+    }
+}
+
+impl<B: OutputBus> WordBus<B> {
+    pub fn write_word<U>(&mut self, value: U) -> Result<(), B::Error>
+    where
+        U: WordWidth,
+        U::Bits: IsLessOrEqual<B::BusWidth>,
+        LeEq<U::Bits, B::BusWidth>: Same<B1>,
+    {
+        let states = states_for_word::<B>(value.into(), self.config);
+        self.bus.write_bus(states)
+    }
+}
+
+impl<B: InputBus> WordBus<B> {
+    pub fn read_word(&self) -> Result<u64, B::Error> {
+        self.bus
+            .read_bus()
+            .map(|states| word_from_states::<B>(&states, self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+
+    use super::*;
+
+    struct MockBus {
+        states: GenericArray<PinState, U8>,
+    }
+
+    impl ParallelBus for MockBus {
+        type BusWidth = U8;
+    }
+
+    impl OutputBus for MockBus {
+        type Error = Infallible;
+
+        fn write_bus(&mut self, states: GenericArray<PinState, U8>) -> Result<(), Self::Error> {
+            self.states = states;
+            Ok(())
+        }
+    }
+
+    impl InputBus for MockBus {
+        type Error = Infallible;
+
+        fn read_bus(&self) -> Result<GenericArray<PinState, U8>, Self::Error> {
+            Ok(self.states)
+        }
+    }
+
+    fn roundtrip(config: WordConfig, value: u8) -> u64 {
+        let mut bus = WordBus::new(
+            MockBus {
+                states: states_for_word::<MockBus>(0, config),
+            },
+            config,
+        );
+        bus.write_word(value).unwrap();
+        bus.read_word().unwrap()
+    }
+
+    #[test]
+    fn roundtrips_lsb_first_active_high() {
+        assert_eq!(roundtrip(WordConfig::default(), 0xA5), 0xA5);
+    }
+
+    #[test]
+    fn roundtrips_msb_first_active_low() {
+        let config = WordConfig {
+            bit_order: BitOrder::MsbFirst,
+            polarity: Polarity::ActiveLow,
+        };
+        assert_eq!(roundtrip(config, 0x3C), 0x3C);
+    }
+}